@@ -80,6 +80,76 @@ pub fn is_valid_file(file_path: &Path) -> std::io::Result<PathBuf> {
     Ok(full_path)
 }
 
+/// A directory path that has already been proven valid by [`AbsoluteDir::new`].
+///
+/// The inner path is always canonicalized and known to point at a directory,
+/// so holders of an `AbsoluteDir` can skip re-validating it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbsoluteDir(PathBuf);
+
+impl AbsoluteDir {
+    /// Validates `directory_path` and wraps it as an `AbsoluteDir`.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory_path` - The directory path to validate and wrap.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(AbsoluteDir)` if the directory is valid, or an `std::io::Error` if it is not.
+    pub fn new(directory_path: &Path) -> std::io::Result<Self> {
+        is_valid_directory(directory_path).map(AbsoluteDir)
+    }
+
+    /// Returns the canonicalized path wrapped by this `AbsoluteDir`.
+    pub fn as_path(&self) -> &Path {
+        &self.0
+    }
+}
+
+/// A file path that has already been proven valid by [`AbsoluteFile::new`].
+///
+/// The inner path is always canonicalized and known to point at a file,
+/// so holders of an `AbsoluteFile` can skip re-validating it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbsoluteFile(PathBuf);
+
+impl AbsoluteFile {
+    /// Validates `file_path` and wraps it as an `AbsoluteFile`.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The file path to validate and wrap.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(AbsoluteFile)` if the file is valid, or an `std::io::Error` if it is not.
+    pub fn new(file_path: &Path) -> std::io::Result<Self> {
+        is_valid_file(file_path).map(AbsoluteFile)
+    }
+
+    /// Returns the canonicalized path wrapped by this `AbsoluteFile`.
+    pub fn as_path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Returns the parent directory of this file as an already-validated `AbsoluteDir`.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(AbsoluteDir)` for the parent directory, or an `std::io::Error` if the
+    /// file has no parent or the parent is not a valid directory.
+    pub fn parent_dir(&self) -> std::io::Result<AbsoluteDir> {
+        match self.0.parent() {
+            Some(parent) => AbsoluteDir::new(parent),
+            None => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("{} has no parent directory", self.0.display()),
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,4 +197,41 @@ mod tests {
         let result = is_valid_file(path);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_absolute_dir_new_valid_directory() {
+        let path = Path::new("test_file");
+        let result = AbsoluteDir::new(path);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_absolute_dir_new_invalid_directory() {
+        let path = Path::new("nonexistent_dir");
+        let result = AbsoluteDir::new(path);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_absolute_file_new_valid_file() {
+        let path = Path::new("test_file/dummy_target_files_dir/file2.pdf");
+        let result = AbsoluteFile::new(path);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_absolute_file_new_invalid_file() {
+        let path = Path::new("nonexistent_file.pdf");
+        let result = AbsoluteFile::new(path);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_absolute_file_parent_dir() {
+        let path = Path::new("test_file/dummy_target_files_dir/file2.pdf");
+        let file = AbsoluteFile::new(path).unwrap();
+        let parent = file.parent_dir().unwrap();
+        let expected = AbsoluteDir::new(Path::new("test_file/dummy_target_files_dir")).unwrap();
+        assert_eq!(parent, expected);
+    }
 }
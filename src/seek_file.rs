@@ -3,7 +3,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::check_path::is_valid_directory;
+use crate::check_path::{is_valid_directory, AbsoluteDir};
 
 /// # 概要
 /// この関数は指定されたディレクトリ内の指定された拡張子のファイルを探し、そのパスのリストを返します。
@@ -55,6 +55,344 @@ pub fn seek_file_by_extension(
     Ok(files)
 }
 
+/// # 概要
+/// `seek_file_by_extension` と同様にファイルを探しますが、呼び出し側が既に
+/// 検証済みの `AbsoluteDir` を持っている場合向けの変種です。ディレクトリの
+/// 有効性チェックを再度行わないため、同じパスを何度も調べる場合の
+/// 再canonicalizeを避けられます。
+///
+/// # 引数
+/// * `directory`: 検証済みのディレクトリを指定します。
+/// * `extension`: ファイルの拡張子を指定します。
+///
+/// # 戻り値
+/// ファイルが見つかった場合はそのパスのリストを返します。
+/// ファイルが見つからなかった場合は空のリストを返します。
+/// 拡張子が指定されていない場合はエラーを返します。
+pub fn seek_file_by_extension_in(
+    directory: &AbsoluteDir,
+    extension: &str,
+) -> std::io::Result<Vec<PathBuf>> {
+    if extension.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Extension is not specified.",
+        ));
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(directory.as_path())? {
+        let entry: fs::DirEntry = entry?;
+        let path: PathBuf = entry.path();
+        if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some(extension) {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// # 概要
+/// `seek_file_by_extension` と同様にファイルを探しますが、見つかったパスは
+/// 絶対パスではなく `base` からの相対パスとして返します。
+///
+/// `base` を起点に `strip_prefix` できない場合（ドライブやルートが異なる場合など）は、
+/// そのエントリについては絶対パスのまま返します。
+///
+/// # 引数
+/// * `directory_path`: ファイルを探すディレクトリのパスを指定します。
+/// * `extension`: ファイルの拡張子を指定します。
+/// * `base`: 返すパスの基準とするディレクトリを指定します。
+///
+/// # 戻り値
+/// ファイルが見つかった場合は `base` からの相対パスのリストを返します。
+/// ファイルが見つからなかった場合は空のリストを返します。
+/// ディレクトリが無効な場合はエラーを返します。
+/// 拡張子が指定されていない場合はエラーを返します。
+pub fn seek_file_by_extension_relative(
+    directory_path: &Path,
+    extension: &str,
+    base: &Path,
+) -> std::io::Result<Vec<PathBuf>> {
+    let files = seek_file_by_extension(directory_path, extension)?;
+    let base = is_valid_directory(base)?;
+    Ok(files
+        .into_iter()
+        .map(|path| match path.strip_prefix(&base) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => path,
+        })
+        .collect())
+}
+
+/// # 概要
+/// `seek_file_by_extension` と同じディレクトリ検証・canonicalize済みパスの仕組みの上に、
+/// 拡張子の完全一致ではなくシェル風のグロブパターン（`*`、`?`、`[...]` の文字クラス、
+/// `{alt1,alt2}` の中括弧展開）でファイル名をマッチングします。
+///
+/// # 引数
+/// * `directory_path`: ファイルを探すディレクトリのパスを指定します。
+/// * `pattern`: ファイル名にマッチさせるグロブパターンを指定します（例: `report_*.pdf`、`*.{log,txt}`）。
+///
+/// # 戻り値
+/// ファイルが見つかった場合はそのパスのリストを返します。
+/// ファイルが見つからなかった場合は空のリストを返します。
+/// ディレクトリが無効な場合はエラーを返します。
+/// パターンが指定されていない場合はエラーを返します。
+pub fn seek_file_by_pattern(directory_path: &Path, pattern: &str) -> std::io::Result<Vec<PathBuf>> {
+    let src_dir: PathBuf = is_valid_directory(directory_path)?;
+    if pattern.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Pattern is not specified.",
+        ));
+    }
+    let alternatives = expand_braces(pattern);
+    let mut files = Vec::new();
+    for entry in fs::read_dir(src_dir.as_path())? {
+        let entry: fs::DirEntry = entry?;
+        let path: PathBuf = entry.path();
+        let file_name = match path.file_name().and_then(|s| s.to_str()) {
+            Some(name) => name,
+            None => continue,
+        };
+        let matches = alternatives
+            .iter()
+            .any(|alt| glob_match(alt.as_str(), file_name));
+        if path.is_file() && matches {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// `{alt1,alt2,...}` を含むグロブパターンを、各選択肢を展開した複数のパターンに変換する。
+/// 中括弧を含まない場合は `pattern` をそのまま1件のリストとして返す。入れ子の中括弧には対応しない。
+fn expand_braces(pattern: &str) -> Vec<String> {
+    if let Some(open) = pattern.find('{') {
+        if let Some(close_rel) = pattern[open..].find('}') {
+            let close = open + close_rel;
+            let prefix = &pattern[..open];
+            let inside = &pattern[open + 1..close];
+            let suffix = &pattern[close + 1..];
+            return inside
+                .split(',')
+                .map(|alt| format!("{}{}{}", prefix, alt, suffix))
+                .collect();
+        }
+    }
+    vec![pattern.to_string()]
+}
+
+/// シェル風のグロブパターン `pattern` が `name` 全体にマッチするかどうかを判定する。
+/// `*` は任意長の文字列、`?` は任意の1文字、`[...]` は文字クラス（`!`/`^` で否定、`a-z` で範囲）を表す。
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let mut p = 0usize;
+    let mut n = 0usize;
+    let mut star_p: Option<usize> = None;
+    let mut star_n = 0usize;
+
+    while n < name.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            star_p = Some(p);
+            star_n = n;
+            p += 1;
+        } else if let Some(next_p) = (p < pattern.len())
+            .then(|| match_one(&pattern, p, name[n]))
+            .flatten()
+        {
+            p = next_p;
+            n += 1;
+        } else if let Some(sp) = star_p {
+            p = sp + 1;
+            star_n += 1;
+            n = star_n;
+        } else {
+            return false;
+        }
+    }
+    while p < pattern.len() && pattern[p] == '*' {
+        p += 1;
+    }
+    p == pattern.len()
+}
+
+/// `pattern[p]` にある1つのパターン要素（リテラル文字・`?`・`[...]` クラス）が `c` にマッチするか判定し、
+/// マッチした場合はその要素を消費した後のパターン位置を返す。
+fn match_one(pattern: &[char], p: usize, c: char) -> Option<usize> {
+    match pattern.get(p) {
+        Some('?') => Some(p + 1),
+        Some('[') => match_class(pattern, p, c),
+        Some(&literal) => {
+            if literal == c {
+                Some(p + 1)
+            } else {
+                None
+            }
+        }
+        None => None,
+    }
+}
+
+/// `pattern[p]` から始まる `[...]` 文字クラスを解析し、`c` にマッチするか判定する。
+/// マッチした場合は閉じ括弧の次のパターン位置を返す。クラスが閉じていない場合はマッチしない。
+fn match_class(pattern: &[char], p: usize, c: char) -> Option<usize> {
+    let mut i = p + 1;
+    let negate = matches!(pattern.get(i), Some('!') | Some('^'));
+    if negate {
+        i += 1;
+    }
+    let start = i;
+    let mut matched = false;
+    while i < pattern.len() && (pattern[i] != ']' || i == start) {
+        if i + 2 < pattern.len() && pattern[i + 1] == '-' && pattern[i + 2] != ']' {
+            let (lo, hi) = (pattern[i], pattern[i + 2]);
+            if lo <= c && c <= hi {
+                matched = true;
+            }
+            i += 3;
+        } else {
+            if pattern[i] == c {
+                matched = true;
+            }
+            i += 1;
+        }
+    }
+    if i >= pattern.len() {
+        return None;
+    }
+    let end = i;
+    let result = if negate { !matched } else { matched };
+    if result {
+        Some(end + 1)
+    } else {
+        None
+    }
+}
+
+/// 画像ファイルとして扱う拡張子の一覧。`seek_image_files` で使用します。
+pub const IMAGE_EXTS: &[&str] = &["jpg", "jpeg", "png", "webp", "gif"];
+
+/// # 概要
+/// この関数は指定されたディレクトリ内の指定された複数の拡張子のいずれかに一致する
+/// ファイルを探し、そのパスのリストを返します。拡張子の比較は大文字小文字を区別しません。
+///
+/// # 引数
+/// * `directory_path`: ファイルを探すディレクトリのパスを指定します。
+/// * `extensions`: ファイルの拡張子のリストを指定します。
+///
+/// # 戻り値
+/// ファイルが見つかった場合はそのパスのリストを返します。
+/// ファイルが見つからなかった場合は空のリストを返します。
+/// ディレクトリが無効な場合はエラーを返します。
+/// 拡張子が1つも指定されていない場合はエラーを返します。
+pub fn seek_file_by_extensions(
+    directory_path: &Path,
+    extensions: &[&str],
+) -> std::io::Result<Vec<PathBuf>> {
+    let src_dir: PathBuf = is_valid_directory(directory_path)?;
+    if extensions.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Extension is not specified.",
+        ));
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(src_dir.as_path())? {
+        let entry: fs::DirEntry = entry?;
+        let path: PathBuf = entry.path();
+        let matches = path
+            .extension()
+            .and_then(|s| s.to_str())
+            .is_some_and(|ext| extensions.iter().any(|e| e.eq_ignore_ascii_case(ext)));
+        if path.is_file() && matches {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// # 概要
+/// `IMAGE_EXTS` に含まれる拡張子を持つ画像ファイルを指定されたディレクトリから探します。
+/// `seek_file_by_extensions` の薄いラッパーです。
+///
+/// # 引数
+/// * `directory_path`: ファイルを探すディレクトリのパスを指定します。
+///
+/// # 戻り値
+/// 画像ファイルが見つかった場合はそのパスのリストを返します。
+/// ディレクトリが無効な場合はエラーを返します。
+pub fn seek_image_files(directory_path: &Path) -> std::io::Result<Vec<PathBuf>> {
+    seek_file_by_extensions(directory_path, IMAGE_EXTS)
+}
+
+/// # 概要
+/// この関数は指定されたディレクトリ以下をサブディレクトリも含めて再帰的に探索し、
+/// 指定された拡張子のファイルを探してそのパスのリストを返します。
+///
+/// 読み取りに失敗したエントリ（権限不足など）は無視して走査を継続し、
+/// 走査全体を中断しません。
+///
+/// # 引数
+/// * `directory_path`: ファイルを探すディレクトリのパスを指定します。
+/// * `extension`: ファイルの拡張子を指定します。
+/// * `max_depth`: 再帰する最大の深さを指定します。`None` の場合は無制限に再帰します。
+///   `directory_path` 直下を深さ `0` として数えます。
+///
+/// # 戻り値
+/// ファイルが見つかった場合はそのパスのリストを返します。
+/// ファイルが見つからなかった場合は空のリストを返します。
+/// ディレクトリが無効な場合はエラーを返します。
+/// 拡張子が指定されていない場合はエラーを返します。
+pub fn seek_file_by_extension_recursive(
+    directory_path: &Path,
+    extension: &str,
+    max_depth: Option<usize>,
+) -> std::io::Result<Vec<PathBuf>> {
+    let src_dir: PathBuf = is_valid_directory(directory_path)?;
+    if extension.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Extension is not specified.",
+        ));
+    }
+    let mut files = Vec::new();
+    walk_dir_for_extension(src_dir.as_path(), extension, max_depth, 0, &mut files);
+    Ok(files)
+}
+
+/// ディレクトリを再帰的に走査し、拡張子が一致するファイルを `files` に積み上げる内部ヘルパー。
+/// エントリの読み取りに失敗した場合はそのエントリだけを読み飛ばし、走査全体は継続する。
+fn walk_dir_for_extension(
+    dir: &Path,
+    extension: &str,
+    max_depth: Option<usize>,
+    depth: usize,
+    files: &mut Vec<PathBuf>,
+) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(_) => continue,
+        };
+        let path: PathBuf = entry.path();
+        if path.is_dir() {
+            if max_depth.map_or(true, |max| depth < max) {
+                walk_dir_for_extension(path.as_path(), extension, max_depth, depth + 1, files);
+            }
+        } else if path.is_file() && path.extension().and_then(|s| s.to_str()) == Some(extension) {
+            if let Ok(canonical) = fs::canonicalize(&path) {
+                files.push(canonical);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +446,219 @@ mod tests {
         let files = seek_file_by_extension(temp_dir.as_path(), "pdf").unwrap();
         assert_eq!(files.len(), 0);
     }
+
+    #[test]
+    fn test_seek_file_by_pattern_not_specifiled_pattern() {
+        let temp_dir: PathBuf = PathBuf::from("test_file/dummy_target_files_dir");
+        let result = seek_file_by_pattern(temp_dir.as_path(), "");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_seek_file_by_pattern_wildcard() {
+        let temp_dir: PathBuf = PathBuf::from("test_file/dummy_target_files_dir");
+        let full_path_file1 = fs::canonicalize(temp_dir.join("file1.pdf")).unwrap();
+        let full_path_file2 = fs::canonicalize(temp_dir.join("file2.pdf")).unwrap();
+        let full_path_file3 = fs::canonicalize(temp_dir.join("file3.pdf")).unwrap();
+
+        let files = seek_file_by_pattern(temp_dir.as_path(), "file?.pdf").unwrap();
+        assert_eq!(files.len(), 3);
+        assert!(files.contains(&full_path_file1));
+        assert!(files.contains(&full_path_file2));
+        assert!(files.contains(&full_path_file3));
+    }
+
+    #[test]
+    fn test_seek_file_by_pattern_prefix_suffix() {
+        let root_dir: PathBuf = PathBuf::from("test_file/dummy_pattern_dir");
+        fs::create_dir_all(root_dir.as_path()).expect("COULD NOT MAKE DIRECTORY.");
+
+        let matching = root_dir.join("report_final.pdf");
+        fs::write(matching.as_path(), b"report").expect("COULD NOT WRITE FILE.");
+        let non_matching = root_dir.join("summary.pdf");
+        fs::write(non_matching.as_path(), b"summary").expect("COULD NOT WRITE FILE.");
+
+        let full_matching = fs::canonicalize(matching.as_path()).unwrap();
+
+        let files = seek_file_by_pattern(root_dir.as_path(), "report_*.pdf").unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files.contains(&full_matching));
+
+        fs::remove_dir_all(root_dir.as_path()).expect("COULD NOT REMOVE DIRECTORY.");
+    }
+
+    #[test]
+    fn test_seek_file_by_pattern_brace_alternation() {
+        let root_dir: PathBuf = PathBuf::from("test_file/dummy_pattern_brace_dir");
+        fs::create_dir_all(root_dir.as_path()).expect("COULD NOT MAKE DIRECTORY.");
+
+        let log_file = root_dir.join("app.log");
+        fs::write(log_file.as_path(), b"log").expect("COULD NOT WRITE FILE.");
+        let txt_file = root_dir.join("app.txt");
+        fs::write(txt_file.as_path(), b"txt").expect("COULD NOT WRITE FILE.");
+        let other_file = root_dir.join("app.csv");
+        fs::write(other_file.as_path(), b"csv").expect("COULD NOT WRITE FILE.");
+
+        let full_log = fs::canonicalize(log_file.as_path()).unwrap();
+        let full_txt = fs::canonicalize(txt_file.as_path()).unwrap();
+
+        let files = seek_file_by_pattern(root_dir.as_path(), "*.{log,txt}").unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.contains(&full_log));
+        assert!(files.contains(&full_txt));
+
+        fs::remove_dir_all(root_dir.as_path()).expect("COULD NOT REMOVE DIRECTORY.");
+    }
+
+    #[test]
+    fn test_glob_match_character_class() {
+        assert!(glob_match("file[1-3].pdf", "file2.pdf"));
+        assert!(!glob_match("file[1-3].pdf", "file4.pdf"));
+        assert!(glob_match("file[!0-9].pdf", "fileA.pdf"));
+        assert!(!glob_match("file[!0-9].pdf", "file1.pdf"));
+    }
+
+    #[test]
+    fn test_seek_file_by_extension_in_with_files() {
+        let temp_dir: PathBuf = PathBuf::from("test_file/dummy_target_files_dir");
+        let absolute_dir = AbsoluteDir::new(temp_dir.as_path()).unwrap();
+
+        let full_path_file1 = fs::canonicalize(temp_dir.join("file1.pdf")).unwrap();
+
+        let files = seek_file_by_extension_in(&absolute_dir, "pdf").unwrap();
+        assert_eq!(files.len(), 3);
+        assert!(files.contains(&full_path_file1));
+    }
+
+    #[test]
+    fn test_seek_file_by_extension_in_not_specifiled_extension() {
+        let temp_dir: PathBuf = PathBuf::from("test_file/dummy_target_files_dir");
+        let absolute_dir = AbsoluteDir::new(temp_dir.as_path()).unwrap();
+        let result = seek_file_by_extension_in(&absolute_dir, "");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_seek_file_by_extension_relative_strips_base() {
+        let temp_dir: PathBuf = PathBuf::from("test_file/dummy_target_files_dir");
+
+        let files =
+            seek_file_by_extension_relative(temp_dir.as_path(), "pdf", Path::new("test_file"))
+                .unwrap();
+        assert_eq!(files.len(), 3);
+        assert!(files.contains(&PathBuf::from("dummy_target_files_dir/file1.pdf")));
+        assert!(files.contains(&PathBuf::from("dummy_target_files_dir/file2.pdf")));
+        assert!(files.contains(&PathBuf::from("dummy_target_files_dir/file3.pdf")));
+    }
+
+    #[test]
+    fn test_seek_file_by_extension_relative_falls_back_to_absolute() {
+        let temp_dir: PathBuf = PathBuf::from("test_file/dummy_target_files_dir");
+        let unrelated_base: PathBuf = PathBuf::from("test_file/empty_dir");
+        fs::create_dir_all(unrelated_base.as_path()).expect("COULD NOT MAKE DIRECTORY.");
+
+        let full_path_file1 =
+            fs::canonicalize(temp_dir.join("file1.pdf")).unwrap();
+
+        let files = seek_file_by_extension_relative(
+            temp_dir.as_path(),
+            "pdf",
+            unrelated_base.as_path(),
+        )
+        .unwrap();
+        assert!(files.contains(&full_path_file1));
+
+        fs::remove_dir_all(unrelated_base.as_path()).expect("COULD NOT REMOVE DIRECTORY.");
+    }
+
+    #[test]
+    fn test_seek_file_by_extensions_not_specifiled_extension() {
+        let temp_dir: PathBuf = PathBuf::from("test_file/dummy_target_files_dir");
+        let result = seek_file_by_extensions(temp_dir.as_path(), &[]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_seek_file_by_extensions_case_insensitive() {
+        let root_dir: PathBuf = PathBuf::from("test_file/dummy_mixed_case_dir");
+        fs::create_dir_all(root_dir.as_path()).expect("COULD NOT MAKE DIRECTORY.");
+
+        let pdf_file = root_dir.join("report.PDF");
+        fs::write(pdf_file.as_path(), b"pdf").expect("COULD NOT WRITE FILE.");
+        let txt_file = root_dir.join("notes.txt");
+        fs::write(txt_file.as_path(), b"txt").expect("COULD NOT WRITE FILE.");
+
+        let full_pdf = fs::canonicalize(pdf_file.as_path()).unwrap();
+
+        let files = seek_file_by_extensions(root_dir.as_path(), &["pdf"]).unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files.contains(&full_pdf));
+
+        fs::remove_dir_all(root_dir.as_path()).expect("COULD NOT REMOVE DIRECTORY.");
+    }
+
+    #[test]
+    fn test_seek_image_files() {
+        let root_dir: PathBuf = PathBuf::from("test_file/dummy_image_dir");
+        fs::create_dir_all(root_dir.as_path()).expect("COULD NOT MAKE DIRECTORY.");
+
+        let png_file = root_dir.join("pic.png");
+        fs::write(png_file.as_path(), b"png").expect("COULD NOT WRITE FILE.");
+        let txt_file = root_dir.join("notes.txt");
+        fs::write(txt_file.as_path(), b"txt").expect("COULD NOT WRITE FILE.");
+
+        let full_png = fs::canonicalize(png_file.as_path()).unwrap();
+
+        let files = seek_image_files(root_dir.as_path()).unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files.contains(&full_png));
+
+        fs::remove_dir_all(root_dir.as_path()).expect("COULD NOT REMOVE DIRECTORY.");
+    }
+
+    #[test]
+    fn test_seek_file_by_extension_recursive_not_specifiled_extension() {
+        let temp_dir: PathBuf = PathBuf::from("test_file/dummy_target_files_dir");
+        let result = seek_file_by_extension_recursive(temp_dir.as_path(), "", None);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_seek_file_by_extension_recursive_with_nested_files() {
+        let root_dir: PathBuf = PathBuf::from("test_file/dummy_recursive_dir");
+        let nested_dir: PathBuf = root_dir.join("nested");
+        fs::create_dir_all(nested_dir.as_path()).expect("COULD NOT MAKE DIRECTORY.");
+
+        let top_file = root_dir.join("top.txt");
+        fs::write(top_file.as_path(), b"top").expect("COULD NOT WRITE FILE.");
+        let nested_file = nested_dir.join("nested.txt");
+        fs::write(nested_file.as_path(), b"nested").expect("COULD NOT WRITE FILE.");
+
+        let full_top = fs::canonicalize(top_file.as_path()).unwrap();
+        let full_nested = fs::canonicalize(nested_file.as_path()).unwrap();
+
+        let files = seek_file_by_extension_recursive(root_dir.as_path(), "txt", None).unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(files.contains(&full_top));
+        assert!(files.contains(&full_nested));
+
+        fs::remove_dir_all(root_dir.as_path()).expect("COULD NOT REMOVE DIRECTORY.");
+    }
+
+    #[test]
+    fn test_seek_file_by_extension_recursive_respects_max_depth() {
+        let root_dir: PathBuf = PathBuf::from("test_file/dummy_recursive_depth_dir");
+        let nested_dir: PathBuf = root_dir.join("nested");
+        fs::create_dir_all(nested_dir.as_path()).expect("COULD NOT MAKE DIRECTORY.");
+
+        let top_file = root_dir.join("top.txt");
+        fs::write(top_file.as_path(), b"top").expect("COULD NOT WRITE FILE.");
+        let nested_file = nested_dir.join("nested.txt");
+        fs::write(nested_file.as_path(), b"nested").expect("COULD NOT WRITE FILE.");
+
+        let files = seek_file_by_extension_recursive(root_dir.as_path(), "txt", Some(0)).unwrap();
+        assert_eq!(files.len(), 1);
+
+        fs::remove_dir_all(root_dir.as_path()).expect("COULD NOT REMOVE DIRECTORY.");
+    }
 }
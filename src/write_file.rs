@@ -0,0 +1,142 @@
+use std::collections::hash_map::RandomState;
+use std::fs;
+use std::fs::OpenOptions;
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+
+/// Builds a sibling temporary path for `path` by replacing its extension with a
+/// short random suffix followed by `.tmp`, e.g. `report.txt` becomes `report.a1b2c3d4.tmp`.
+fn temp_sibling_path(path: &Path) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u32(std::process::id());
+    hasher.write_u128(nanos);
+    let suffix = format!("{:08x}", hasher.finish() as u32);
+    path.with_extension(format!("{}.tmp", suffix))
+}
+
+/// Writes `data` to `path` without ever exposing a half-written file to readers.
+///
+/// The bytes are first written to a sibling temporary file, then `fs::rename` is
+/// used to atomically move it into place over `path`.
+///
+/// # Arguments
+///
+/// * `path` - The destination path to write to.
+/// * `data` - The bytes to write.
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or an `std::io::Error` if the write or rename fails.
+pub fn atomic_write_file(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let temp_path = temp_sibling_path(path);
+    fs::write(&temp_path, data)?;
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+/// Writes `data` to `path` with explicit control over creation, appending, and
+/// (on Unix) the file's permission bits.
+///
+/// # Arguments
+///
+/// * `path` - The destination path to write to.
+/// * `data` - The bytes to write.
+/// * `create` - Whether the file may be created if it does not already exist.
+/// * `append` - Whether to append to the file instead of truncating it.
+/// * `mode` - On Unix, the permission bits to apply via `PermissionsExt::from_mode`.
+///   Ignored on other platforms.
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or an `std::io::Error` if the file cannot be
+/// opened or written to.
+pub fn write_file_2(
+    path: &Path,
+    data: &[u8],
+    create: bool,
+    append: bool,
+    mode: Option<u32>,
+) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let file = OpenOptions::new()
+        .write(true)
+        .create(create)
+        .append(append)
+        .truncate(!append)
+        .open(path)?;
+
+    #[cfg(unix)]
+    if let Some(mode) = mode {
+        let permissions = fs::Permissions::from_mode(mode);
+        file.set_permissions(permissions)?;
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+
+    let mut file = file;
+    file.write_all(data)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atomic_write_file_creates_file_with_contents() {
+        let path = Path::new("test_file/atomic_write_target.txt");
+        atomic_write_file(path, b"hello atomic world").expect("ATOMIC WRITE FAILED.");
+        let contents = fs::read(path).expect("COULD NOT READ FILE.");
+        assert_eq!(contents, b"hello atomic world");
+        fs::remove_file(path).expect("COULD NOT REMOVE FILE.");
+    }
+
+    #[test]
+    fn test_atomic_write_file_replaces_existing_contents() {
+        let path = Path::new("test_file/atomic_write_overwrite.txt");
+        fs::write(path, b"old contents").expect("COULD NOT WRITE FILE.");
+        atomic_write_file(path, b"new contents").expect("ATOMIC WRITE FAILED.");
+        let contents = fs::read(path).expect("COULD NOT READ FILE.");
+        assert_eq!(contents, b"new contents");
+        fs::remove_file(path).expect("COULD NOT REMOVE FILE.");
+    }
+
+    #[test]
+    fn test_write_file_2_create_and_truncate() {
+        let path = Path::new("test_file/write_file_2_create.txt");
+        write_file_2(path, b"first", true, false, None).expect("WRITE FAILED.");
+        write_file_2(path, b"second", true, false, None).expect("WRITE FAILED.");
+        let contents = fs::read(path).expect("COULD NOT READ FILE.");
+        assert_eq!(contents, b"second");
+        fs::remove_file(path).expect("COULD NOT REMOVE FILE.");
+    }
+
+    #[test]
+    fn test_write_file_2_append() {
+        let path = Path::new("test_file/write_file_2_append.txt");
+        write_file_2(path, b"first", true, false, None).expect("WRITE FAILED.");
+        write_file_2(path, b"second", true, true, None).expect("WRITE FAILED.");
+        let contents = fs::read(path).expect("COULD NOT READ FILE.");
+        assert_eq!(contents, b"firstsecond");
+        fs::remove_file(path).expect("COULD NOT REMOVE FILE.");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_file_2_applies_mode() {
+        let path = Path::new("test_file/write_file_2_mode.txt");
+        write_file_2(path, b"data", true, false, Some(0o600)).expect("WRITE FAILED.");
+        let permissions = fs::metadata(path).expect("COULD NOT READ METADATA.").permissions();
+        assert_eq!(permissions.mode() & 0o777, 0o600);
+        fs::remove_file(path).expect("COULD NOT REMOVE FILE.");
+    }
+}